@@ -0,0 +1,146 @@
+//! Headless CLI for running and inspecting the StocksBot trading backend
+//! without the Tauri GUI, sharing sidecar/credential/health logic with it
+//! via `stocksbot-core`.
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use clap::{Parser, Subcommand};
+use stocksbot_core::credentials;
+use stocksbot_core::health::{self, BackendConfig};
+use stocksbot_core::sidecar::{self, LaunchOutcome};
+
+#[derive(Parser)]
+#[command(name = "stocksbot", about = "Run and inspect the StocksBot backend from the command line")]
+struct Cli {
+    /// Backend host, overriding the 127.0.0.1 default.
+    #[arg(long, global = true, default_value = "127.0.0.1")]
+    host: String,
+
+    /// Backend port, overriding the 8000 default.
+    #[arg(long, global = true, default_value_t = 8000)]
+    port: u16,
+
+    /// Treat the backend as externally managed: never spawn a local sidecar.
+    #[arg(long, global = true)]
+    external: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Launch the backend sidecar (if needed) and wait for it to become healthy.
+    Run {
+        /// Trading mode passed through to the backend, e.g. "paper" or "live".
+        #[arg(long, default_value = "paper")]
+        mode: String,
+    },
+    /// Report whether the backend is currently reachable and healthy.
+    Status,
+    /// Manage stored Alpaca API credentials.
+    Creds {
+        #[command(subcommand)]
+        action: CredsAction,
+    },
+}
+
+#[derive(Subcommand)]
+enum CredsAction {
+    /// Store an API key/secret pair for a given mode.
+    Set {
+        #[arg(long)]
+        mode: String,
+        #[arg(long)]
+        api_key: String,
+        #[arg(long)]
+        secret_key: String,
+    },
+    /// Show whether paper/live credentials are present.
+    Status,
+    /// Remove stored credentials for a given mode.
+    Clear {
+        #[arg(long)]
+        mode: String,
+    },
+}
+
+fn main() {
+    let cli = Cli::parse();
+    let config = BackendConfig {
+        host: cli.host,
+        port: cli.port,
+        external: cli.external,
+    };
+
+    let result = match cli.command {
+        Command::Run { mode } => run(&config, &mode),
+        Command::Status => status(&config),
+        Command::Creds { action } => creds(action),
+    };
+
+    if let Err(e) = result {
+        eprintln!("error: {}", e);
+        std::process::exit(1);
+    }
+}
+
+fn run(config: &BackendConfig, mode: &str) -> Result<(), String> {
+    let normalized_mode = credentials::validate_mode(mode)?;
+    let search_dirs: Vec<PathBuf> = Vec::new();
+
+    match sidecar::launch_backend_sidecar(config, Some(&normalized_mode), &search_dirs) {
+        LaunchOutcome::External => {
+            println!("External backend configured at {}; not spawning a sidecar", config.addr());
+        }
+        LaunchOutcome::AlreadyReachable => {
+            println!("Backend already reachable at {}", config.addr());
+        }
+        LaunchOutcome::Spawned(mut child) => {
+            println!("Launched backend sidecar (mode: {})", normalized_mode);
+            if health::wait_for_backend_ready(&config.base_url(), 60, Duration::from_millis(500)) {
+                println!("Backend is healthy and ready");
+            } else {
+                println!("Warning: backend launched but not responding to health checks yet");
+            }
+            let _ = child.wait();
+            return Ok(());
+        }
+        LaunchOutcome::NotFound => {
+            return Err("backend sidecar binary/script not found; run it manually if needed".to_string());
+        }
+    }
+
+    if health::wait_for_backend_ready(&config.base_url(), 60, Duration::from_millis(500)) {
+        println!("Backend is healthy and ready");
+    } else {
+        println!("Warning: backend is not responding to health checks yet");
+    }
+    Ok(())
+}
+
+fn status(config: &BackendConfig) -> Result<(), String> {
+    let healthy = health::is_backend_healthy(&config.base_url());
+    println!("Backend {}: {}", config.addr(), if healthy { "healthy" } else { "unreachable" });
+    Ok(())
+}
+
+fn creds(action: CredsAction) -> Result<(), String> {
+    match action {
+        CredsAction::Set { mode, api_key, secret_key } => {
+            credentials::save_credentials(&mode, &api_key, &secret_key)?;
+            println!("Saved credentials for mode {}", mode);
+        }
+        CredsAction::Status => {
+            let status = credentials::get_credentials_status()?;
+            println!("paper: {}", if status.paper_available { "available" } else { "missing" });
+            println!("live: {}", if status.live_available { "available" } else { "missing" });
+        }
+        CredsAction::Clear { mode } => {
+            credentials::clear_credentials(&mode)?;
+            println!("Cleared credentials for mode {}", mode);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,120 @@
+//! Keyring-backed Alpaca API credential storage, shared by the GUI and CLI.
+
+use serde::{Deserialize, Serialize};
+
+const KEYCHAIN_SERVICE: &str = "com.stocksbot.alpaca";
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CredentialStatus {
+    pub paper_available: bool,
+    pub live_available: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AlpacaCredentials {
+    pub api_key: String,
+    pub secret_key: String,
+}
+
+pub fn credential_username(mode: &str, field: &str) -> String {
+    format!("{}_{}", mode, field)
+}
+
+/// Opens a keyring entry for `mode`/`field` under StocksBot's keychain
+/// service. Exposed so callers with a custom field (e.g. the GUI's vault
+/// layer) can reuse the same service/username scheme.
+pub fn keyring_entry(mode: &str, field: &str) -> Result<keyring::Entry, String> {
+    keyring::Entry::new(KEYCHAIN_SERVICE, &credential_username(mode, field)).map_err(|e| e.to_string())
+}
+
+pub fn validate_mode(mode: &str) -> Result<String, String> {
+    let normalized = mode.trim().to_lowercase();
+    if normalized != "paper" && normalized != "live" {
+        return Err("mode must be paper or live".to_string());
+    }
+    Ok(normalized)
+}
+
+pub fn validate_key_material(value: &str, field: &str) -> Result<String, String> {
+    let trimmed = value.trim();
+    if trimmed.is_empty() {
+        return Err(format!("{} is required", field));
+    }
+    if trimmed.len() < 8 {
+        return Err(format!("{} appears too short", field));
+    }
+    if trimmed.len() > 512 {
+        return Err(format!("{} is too long", field));
+    }
+    if trimmed.chars().any(|c| c.is_whitespace()) {
+        return Err(format!("{} cannot contain whitespace", field));
+    }
+    Ok(trimmed.to_string())
+}
+
+pub fn save_credentials(mode: &str, api_key: &str, secret_key: &str) -> Result<(), String> {
+    let normalized_mode = validate_mode(mode)?;
+    let sanitized_api_key = validate_key_material(api_key, "api_key")?;
+    let sanitized_secret_key = validate_key_material(secret_key, "secret_key")?;
+
+    let api_entry = keyring::Entry::new(KEYCHAIN_SERVICE, &credential_username(&normalized_mode, "api_key"))
+        .map_err(|e| e.to_string())?;
+    let secret_entry = keyring::Entry::new(KEYCHAIN_SERVICE, &credential_username(&normalized_mode, "secret_key"))
+        .map_err(|e| e.to_string())?;
+
+    api_entry.set_password(&sanitized_api_key).map_err(|e| e.to_string())?;
+    secret_entry.set_password(&sanitized_secret_key).map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+pub fn get_credentials(mode: &str) -> Result<Option<AlpacaCredentials>, String> {
+    let normalized_mode = validate_mode(mode)?;
+
+    let api_entry = keyring::Entry::new(KEYCHAIN_SERVICE, &credential_username(&normalized_mode, "api_key"))
+        .map_err(|e| e.to_string())?;
+    let secret_entry = keyring::Entry::new(KEYCHAIN_SERVICE, &credential_username(&normalized_mode, "secret_key"))
+        .map_err(|e| e.to_string())?;
+
+    let api_key = match api_entry.get_password() {
+        Ok(value) => value,
+        Err(_) => return Ok(None),
+    };
+    let secret_key = match secret_entry.get_password() {
+        Ok(value) => value,
+        Err(_) => return Ok(None),
+    };
+
+    Ok(Some(AlpacaCredentials { api_key, secret_key }))
+}
+
+pub fn get_credentials_status() -> Result<CredentialStatus, String> {
+    let paper_api = keyring::Entry::new(KEYCHAIN_SERVICE, &credential_username("paper", "api_key"))
+        .map_err(|e| e.to_string())?;
+    let paper_secret = keyring::Entry::new(KEYCHAIN_SERVICE, &credential_username("paper", "secret_key"))
+        .map_err(|e| e.to_string())?;
+    let live_api = keyring::Entry::new(KEYCHAIN_SERVICE, &credential_username("live", "api_key"))
+        .map_err(|e| e.to_string())?;
+    let live_secret = keyring::Entry::new(KEYCHAIN_SERVICE, &credential_username("live", "secret_key"))
+        .map_err(|e| e.to_string())?;
+
+    let paper_available = paper_api.get_password().is_ok() && paper_secret.get_password().is_ok();
+    let live_available = live_api.get_password().is_ok() && live_secret.get_password().is_ok();
+
+    Ok(CredentialStatus {
+        paper_available,
+        live_available,
+    })
+}
+
+pub fn clear_credentials(mode: &str) -> Result<(), String> {
+    let normalized_mode = validate_mode(mode)?;
+
+    let api_entry = keyring::Entry::new(KEYCHAIN_SERVICE, &credential_username(&normalized_mode, "api_key"))
+        .map_err(|e| e.to_string())?;
+    let secret_entry = keyring::Entry::new(KEYCHAIN_SERVICE, &credential_username(&normalized_mode, "secret_key"))
+        .map_err(|e| e.to_string())?;
+
+    let _ = api_entry.delete_password();
+    let _ = secret_entry.delete_password();
+    Ok(())
+}
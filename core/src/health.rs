@@ -0,0 +1,85 @@
+//! Backend address configuration and health polling, shared by the GUI and
+//! CLI so they agree on what "healthy" means.
+
+use std::net::{SocketAddr, TcpStream};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackendConfig {
+    pub host: String,
+    pub port: u16,
+    /// When true, the backend is assumed to be managed elsewhere: callers
+    /// should only monitor it and never spawn the local PyInstaller sidecar.
+    pub external: bool,
+}
+
+impl Default for BackendConfig {
+    fn default() -> Self {
+        Self {
+            host: "127.0.0.1".to_string(),
+            port: 8000,
+            external: false,
+        }
+    }
+}
+
+impl BackendConfig {
+    pub fn addr(&self) -> String {
+        format!("{}:{}", self.host, self.port)
+    }
+
+    pub fn base_url(&self) -> String {
+        format!("http://{}", self.addr())
+    }
+}
+
+/// Quick TCP-level reachability check (used before HTTP is available).
+pub fn is_backend_tcp_reachable(addr: &str) -> bool {
+    let addr: SocketAddr = match addr.parse() {
+        Ok(parsed) => parsed,
+        Err(_) => return false,
+    };
+    TcpStream::connect_timeout(&addr, Duration::from_millis(350)).is_ok()
+}
+
+/// Full HTTP health check — confirms the backend is responding to requests.
+pub fn is_backend_healthy(base_url: &str) -> bool {
+    let agent = ureq::AgentBuilder::new()
+        .timeout_connect(Duration::from_secs(2))
+        .timeout(Duration::from_secs(5))
+        .build();
+    match agent.get(&format!("{}/status", base_url)).call() {
+        Ok(resp) => resp.status() == 200,
+        Err(_) => false,
+    }
+}
+
+/// Fetches and decodes the backend's full status payload as raw JSON. Callers
+/// that care about specific fields (e.g. the tray) deserialize further.
+pub fn fetch_backend_status(base_url: &str) -> Option<serde_json::Value> {
+    let agent = ureq::AgentBuilder::new()
+        .timeout_connect(Duration::from_secs(2))
+        .timeout(Duration::from_secs(5))
+        .build();
+    let response = agent.get(&format!("{}/status", base_url)).call().ok()?;
+    if response.status() != 200 {
+        return None;
+    }
+    response.into_json::<serde_json::Value>().ok()
+}
+
+/// Waits for the backend to become healthy after launch, polling up to
+/// `max_attempts` times with `interval` after each attempt, plus one final
+/// check after the last interval.
+pub fn wait_for_backend_ready(base_url: &str, max_attempts: u32, interval: Duration) -> bool {
+    for _ in 1..=max_attempts {
+        if is_backend_healthy(base_url) {
+            return true;
+        }
+        std::thread::sleep(interval);
+    }
+    // Final attempt — maybe it just needs one more second
+    is_backend_healthy(base_url)
+}
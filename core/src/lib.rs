@@ -0,0 +1,9 @@
+//! Shared sidecar management, credential access, and health-polling logic
+//! used by both the Tauri GUI and the headless `stocksbot` CLI, so paper/live
+//! behavior is identical whether the bot is driven from the tray or a
+//! terminal.
+
+pub mod credentials;
+pub mod health;
+pub mod persistence;
+pub mod sidecar;
@@ -0,0 +1,180 @@
+//! Pooled SQLite storage for equity/P&L history and trade fills, shared by
+//! the GUI watchdog (writer) and the GUI's query command handlers (readers)
+//! so they never contend on a single connection.
+
+use std::path::Path;
+
+use r2d2_sqlite::SqliteConnectionManager;
+use rusqlite::params;
+use serde::{Deserialize, Serialize};
+
+pub type Pool = r2d2::Pool<SqliteConnectionManager>;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PersistenceConfig {
+    pub enabled: bool,
+    /// Rows older than this many days are pruned on each retention sweep.
+    pub retention_days: u32,
+}
+
+impl Default for PersistenceConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            retention_days: 30,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EquitySnapshotRow {
+    pub recorded_at: i64,
+    pub equity: f64,
+    pub cash: f64,
+    pub daily_pnl: f64,
+    pub daily_pnl_pct: f64,
+    pub open_positions: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TradeLogEntry {
+    pub symbol: String,
+    pub side: String,
+    pub qty: f64,
+    pub price: f64,
+    pub filled_at: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TradeLogRow {
+    pub recorded_at: i64,
+    pub symbol: String,
+    pub side: String,
+    pub qty: f64,
+    pub price: f64,
+}
+
+/// Opens (creating if needed) the history database at `db_path` behind a
+/// connection pool, and ensures its tables exist.
+pub fn init_pool(db_path: &Path) -> Result<Pool, String> {
+    if let Some(parent) = db_path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    let manager = SqliteConnectionManager::file(db_path);
+    let pool = r2d2::Pool::new(manager).map_err(|e| e.to_string())?;
+
+    let conn = pool.get().map_err(|e| e.to_string())?;
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS equity_history (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            recorded_at INTEGER NOT NULL,
+            equity REAL NOT NULL,
+            cash REAL NOT NULL,
+            daily_pnl REAL NOT NULL,
+            daily_pnl_pct REAL NOT NULL,
+            open_positions INTEGER NOT NULL
+        );
+        CREATE INDEX IF NOT EXISTS idx_equity_history_recorded_at ON equity_history(recorded_at);
+
+        CREATE TABLE IF NOT EXISTS trade_log (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            recorded_at INTEGER NOT NULL,
+            symbol TEXT NOT NULL,
+            side TEXT NOT NULL,
+            qty REAL NOT NULL,
+            price REAL NOT NULL
+        );
+        CREATE INDEX IF NOT EXISTS idx_trade_log_recorded_at ON trade_log(recorded_at);",
+    )
+    .map_err(|e| e.to_string())?;
+
+    Ok(pool)
+}
+
+pub fn record_equity_snapshot(pool: &Pool, recorded_at: i64, row: &EquitySnapshotRow) -> Result<(), String> {
+    let conn = pool.get().map_err(|e| e.to_string())?;
+    conn.execute(
+        "INSERT INTO equity_history (recorded_at, equity, cash, daily_pnl, daily_pnl_pct, open_positions)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        params![
+            recorded_at,
+            row.equity,
+            row.cash,
+            row.daily_pnl,
+            row.daily_pnl_pct,
+            row.open_positions as i64,
+        ],
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+pub fn record_trades(pool: &Pool, recorded_at: i64, trades: &[TradeLogEntry]) -> Result<(), String> {
+    let mut conn = pool.get().map_err(|e| e.to_string())?;
+    let tx = conn.transaction().map_err(|e| e.to_string())?;
+    for trade in trades {
+        tx.execute(
+            "INSERT INTO trade_log (recorded_at, symbol, side, qty, price) VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![recorded_at, trade.symbol, trade.side, trade.qty, trade.price],
+        )
+        .map_err(|e| e.to_string())?;
+    }
+    tx.commit().map_err(|e| e.to_string())
+}
+
+pub fn query_equity_history(pool: &Pool, from: i64, to: i64) -> Result<Vec<EquitySnapshotRow>, String> {
+    let conn = pool.get().map_err(|e| e.to_string())?;
+    let mut stmt = conn
+        .prepare(
+            "SELECT recorded_at, equity, cash, daily_pnl, daily_pnl_pct, open_positions
+             FROM equity_history WHERE recorded_at BETWEEN ?1 AND ?2 ORDER BY recorded_at ASC",
+        )
+        .map_err(|e| e.to_string())?;
+    let rows = stmt
+        .query_map(params![from, to], |row| {
+            Ok(EquitySnapshotRow {
+                recorded_at: row.get(0)?,
+                equity: row.get(1)?,
+                cash: row.get(2)?,
+                daily_pnl: row.get(3)?,
+                daily_pnl_pct: row.get(4)?,
+                open_positions: row.get::<_, i64>(5)? as u64,
+            })
+        })
+        .map_err(|e| e.to_string())?;
+    rows.collect::<Result<Vec<_>, _>>().map_err(|e| e.to_string())
+}
+
+pub fn query_trade_log(pool: &Pool, from: i64, to: i64) -> Result<Vec<TradeLogRow>, String> {
+    let conn = pool.get().map_err(|e| e.to_string())?;
+    let mut stmt = conn
+        .prepare(
+            "SELECT recorded_at, symbol, side, qty, price
+             FROM trade_log WHERE recorded_at BETWEEN ?1 AND ?2 ORDER BY recorded_at ASC",
+        )
+        .map_err(|e| e.to_string())?;
+    let rows = stmt
+        .query_map(params![from, to], |row| {
+            Ok(TradeLogRow {
+                recorded_at: row.get(0)?,
+                symbol: row.get(1)?,
+                side: row.get(2)?,
+                qty: row.get(3)?,
+                price: row.get(4)?,
+            })
+        })
+        .map_err(|e| e.to_string())?;
+    rows.collect::<Result<Vec<_>, _>>().map_err(|e| e.to_string())
+}
+
+/// Deletes rows older than `retention_days` from both tables, so the
+/// database doesn't grow unbounded across long-running sessions.
+pub fn enforce_retention(pool: &Pool, now: i64, retention_days: u32) -> Result<(), String> {
+    let cutoff = now - (retention_days as i64) * 86_400;
+    let conn = pool.get().map_err(|e| e.to_string())?;
+    conn.execute("DELETE FROM equity_history WHERE recorded_at < ?1", params![cutoff])
+        .map_err(|e| e.to_string())?;
+    conn.execute("DELETE FROM trade_log WHERE recorded_at < ?1", params![cutoff])
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
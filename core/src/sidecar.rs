@@ -0,0 +1,122 @@
+//! Backend sidecar discovery, launch, and teardown — shared so the GUI
+//! sidecar watchdog and the CLI's `stocksbot run` use the exact same search
+//! order and spawn logic.
+
+use std::path::PathBuf;
+use std::process::{Child, Command, Stdio};
+
+use crate::health::{is_backend_tcp_reachable, BackendConfig};
+
+/// Looks for a PyInstaller-built backend binary, checking the current working
+/// directory's `../backend/dist` / `backend/dist` layout used in development
+/// first, then `search_dirs` (in order).
+pub fn find_backend_binary(search_dirs: &[PathBuf]) -> Option<PathBuf> {
+    let mut candidates: Vec<PathBuf> = Vec::new();
+    if let Ok(cwd) = std::env::current_dir() {
+        candidates.push(cwd.join("../backend/dist/stocksbot-backend"));
+        candidates.push(cwd.join("../backend/dist/stocksbot-backend.exe"));
+        candidates.push(cwd.join("backend/dist/stocksbot-backend"));
+        candidates.push(cwd.join("backend/dist/stocksbot-backend.exe"));
+    }
+    for dir in search_dirs {
+        candidates.push(dir.join("_up_/backend/dist/stocksbot-backend"));
+        candidates.push(dir.join("_up_/backend/dist/stocksbot-backend.exe"));
+        candidates.push(dir.join("backend/dist/stocksbot-backend"));
+        candidates.push(dir.join("backend/dist/stocksbot-backend.exe"));
+        candidates.push(dir.join("stocksbot-backend"));
+        candidates.push(dir.join("stocksbot-backend.exe"));
+    }
+    candidates.into_iter().find(|candidate| candidate.exists() && candidate.is_file())
+}
+
+/// Looks for the Python backend entrypoint script, checking the development
+/// `../backend/app.py` / `backend/app.py` layout first, then `search_dirs`.
+pub fn find_backend_script(search_dirs: &[PathBuf]) -> Option<PathBuf> {
+    let mut candidates: Vec<PathBuf> = Vec::new();
+    if let Ok(cwd) = std::env::current_dir() {
+        candidates.push(cwd.join("../backend/app.py"));
+        candidates.push(cwd.join("backend/app.py"));
+    }
+    for dir in search_dirs {
+        candidates.push(dir.join("_up_/backend/app.py"));
+        candidates.push(dir.join("backend/app.py"));
+        candidates.push(dir.join("app.py"));
+    }
+    candidates.into_iter().find(|candidate| candidate.exists() && candidate.is_file())
+}
+
+/// Result of a sidecar launch attempt, distinguishing "already reachable, so
+/// nothing was spawned" from "found and spawned" and "not found at all" —
+/// the GUI and CLI render each of these differently.
+pub enum LaunchOutcome {
+    AlreadyReachable,
+    External,
+    Spawned(Child),
+    NotFound,
+}
+
+/// Launches the backend sidecar (binary preferred, Python script as
+/// fallback), optionally passing `--mode <mode>` through to it. Returns
+/// without spawning anything if the configured backend is external or
+/// already reachable.
+pub fn launch_backend_sidecar(
+    config: &BackendConfig,
+    mode: Option<&str>,
+    search_dirs: &[PathBuf],
+) -> LaunchOutcome {
+    if config.external {
+        return LaunchOutcome::External;
+    }
+    if is_backend_tcp_reachable(&config.addr()) {
+        return LaunchOutcome::AlreadyReachable;
+    }
+
+    if let Some(binary) = find_backend_binary(search_dirs) {
+        let mut cmd = Command::new(&binary);
+        if let Some(parent) = binary.parent() {
+            cmd.current_dir(parent);
+        }
+        if let Some(mode) = mode {
+            cmd.args(["--mode", mode]);
+        }
+        cmd.stdin(Stdio::null());
+        cmd.stdout(Stdio::null());
+        cmd.stderr(Stdio::null());
+        if let Ok(child) = cmd.spawn() {
+            return LaunchOutcome::Spawned(child);
+        }
+    }
+
+    let Some(script) = find_backend_script(search_dirs) else {
+        return LaunchOutcome::NotFound;
+    };
+
+    for interpreter in ["python3", "python"] {
+        let mut cmd = Command::new(interpreter);
+        cmd.arg(&script);
+        if let Some(mode) = mode {
+            cmd.args(["--mode", mode]);
+        }
+        if let Some(parent) = script.parent() {
+            cmd.current_dir(parent);
+        }
+        cmd.stdin(Stdio::null());
+        cmd.stdout(Stdio::null());
+        cmd.stderr(Stdio::null());
+        if let Ok(child) = cmd.spawn() {
+            return LaunchOutcome::Spawned(child);
+        }
+    }
+
+    LaunchOutcome::NotFound
+}
+
+/// Kills and reaps a sidecar child process, tolerating one that already
+/// exited on its own.
+pub fn stop_sidecar(child: &mut Child) {
+    if let Ok(Some(_)) = child.try_wait() {
+        return; // already exited
+    }
+    let _ = child.kill();
+    let _ = child.wait();
+}
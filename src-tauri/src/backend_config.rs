@@ -0,0 +1,55 @@
+//! User-configurable backend host/port, so the Python backend can run on a
+//! remote machine or non-default port instead of the compile-time default.
+
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use tauri::{AppHandle, Manager};
+
+const BACKEND_CONFIG_FILE: &str = "backend.json";
+
+// The CLI and GUI must agree on the host/port/external shape, so the type
+// itself lives in stocksbot-core; this module only owns its persistence.
+pub use stocksbot_core::health::BackendConfig;
+
+/// Holds the backend config that's actually in effect, so the watchdog
+/// thread always health-checks/relaunches against the current value even
+/// after `set_backend_config` updates it.
+#[derive(Default)]
+pub struct BackendConfigState(pub Mutex<BackendConfig>);
+
+impl BackendConfigState {
+    pub fn current(&self) -> BackendConfig {
+        self.0.lock().map(|guard| guard.clone()).unwrap_or_default()
+    }
+}
+
+pub fn current_backend_config(app: &AppHandle) -> BackendConfig {
+    app.try_state::<BackendConfigState>()
+        .map(|state| state.current())
+        .unwrap_or_default()
+}
+
+fn backend_config_path(app: &AppHandle) -> Option<PathBuf> {
+    app.path().app_config_dir().ok().map(|dir| dir.join(BACKEND_CONFIG_FILE))
+}
+
+pub fn load_backend_config(app: &AppHandle) -> BackendConfig {
+    let Some(path) = backend_config_path(app) else {
+        return BackendConfig::default();
+    };
+    match fs::read_to_string(&path) {
+        Ok(contents) => serde_json::from_str(&contents).unwrap_or_default(),
+        Err(_) => BackendConfig::default(),
+    }
+}
+
+pub fn save_backend_config(app: &AppHandle, config: &BackendConfig) -> Result<(), String> {
+    let path = backend_config_path(app).ok_or_else(|| "could not resolve app config dir".to_string())?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    let contents = serde_json::to_string_pretty(config).map_err(|e| e.to_string())?;
+    fs::write(&path, contents).map_err(|e| e.to_string())
+}
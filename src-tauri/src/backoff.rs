@@ -0,0 +1,51 @@
+//! Exponential backoff with jitter for watchdog auto-restarts, plus the
+//! circuit-breaker bookkeeping that lets a long-lived backend earn its
+//! restart budget back.
+
+use std::time::Duration;
+
+use rand::Rng;
+use serde::Serialize;
+
+const BASE_DELAY: Duration = Duration::from_secs(1);
+const MAX_DELAY: Duration = Duration::from_secs(60);
+const JITTER_FRACTION: f64 = 0.2;
+
+/// Consecutive healthy checks required before the restart counter resets.
+pub const HEALTHY_CHECKS_TO_RESET_BREAKER: u32 = 5;
+
+/// Computes `base * 2^restart_count`, capped at `MAX_DELAY`, with +/-20% jitter.
+pub fn next_restart_delay(restart_count: u32) -> Duration {
+    let exponent = restart_count.min(10); // plenty to saturate past MAX_DELAY
+    let scaled_ms = BASE_DELAY.as_millis().saturating_mul(1u128 << exponent);
+    let capped_ms = scaled_ms.min(MAX_DELAY.as_millis()) as f64;
+    let jitter = rand::thread_rng().gen_range(-JITTER_FRACTION..=JITTER_FRACTION);
+    let jittered_ms = (capped_ms * (1.0 + jitter)).max(0.0);
+    Duration::from_millis(jittered_ms as u64)
+}
+
+/// Payload emitted on `backend-health` so the UI can render e.g. "cooling
+/// down, next retry in Ns" instead of a bare status string.
+#[derive(Debug, Clone, Serialize)]
+pub struct BackendHealthEvent {
+    pub status: String,
+    pub restart_count: u32,
+    pub consecutive_failures: u32,
+    pub next_retry_in_secs: Option<u64>,
+}
+
+impl BackendHealthEvent {
+    pub fn new(
+        status: &str,
+        restart_count: u32,
+        consecutive_failures: u32,
+        next_retry_in: Option<Duration>,
+    ) -> Self {
+        Self {
+            status: status.to_string(),
+            restart_count,
+            consecutive_failures,
+            next_retry_in_secs: next_retry_in.map(|d| d.as_secs()),
+        }
+    }
+}
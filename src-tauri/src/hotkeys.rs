@@ -0,0 +1,115 @@
+//! User-configurable global shortcuts for showing/hiding the window and
+//! toggling the runner from anywhere, not just the tray menu.
+
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut};
+
+const HOTKEYS_CONFIG_FILE: &str = "hotkeys.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HotkeyBinding {
+    pub keys: String,
+    pub enabled: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HotkeysConfig {
+    pub toggle_window: HotkeyBinding,
+    pub toggle_runner: HotkeyBinding,
+}
+
+impl Default for HotkeysConfig {
+    fn default() -> Self {
+        Self {
+            toggle_window: HotkeyBinding {
+                keys: "CmdOrCtrl+Shift+S".to_string(),
+                enabled: true,
+            },
+            toggle_runner: HotkeyBinding {
+                keys: "CmdOrCtrl+Shift+R".to_string(),
+                enabled: true,
+            },
+        }
+    }
+}
+
+/// Which tray action a registered shortcut should fire when pressed.
+pub const ACTION_TOGGLE_WINDOW: &str = "toggle_window";
+pub const ACTION_TOGGLE_RUNNER: &str = "toggle_runner";
+
+/// Tracks the shortcuts currently registered with the OS so the plugin-level
+/// handler can map a fired `Shortcut` back to the action it represents.
+#[derive(Default)]
+pub struct HotkeysState {
+    pub registered: Mutex<Vec<(String, Shortcut)>>,
+}
+
+fn hotkeys_config_path(app: &AppHandle) -> Option<PathBuf> {
+    app.path().app_config_dir().ok().map(|dir| dir.join(HOTKEYS_CONFIG_FILE))
+}
+
+pub fn load_hotkeys_config(app: &AppHandle) -> HotkeysConfig {
+    let Some(path) = hotkeys_config_path(app) else {
+        return HotkeysConfig::default();
+    };
+    match fs::read_to_string(&path) {
+        Ok(contents) => serde_json::from_str(&contents).unwrap_or_default(),
+        Err(_) => HotkeysConfig::default(),
+    }
+}
+
+pub fn save_hotkeys_config(app: &AppHandle, config: &HotkeysConfig) -> Result<(), String> {
+    let path = hotkeys_config_path(app).ok_or_else(|| "could not resolve app config dir".to_string())?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    let contents = serde_json::to_string_pretty(config).map_err(|e| e.to_string())?;
+    fs::write(&path, contents).map_err(|e| e.to_string())
+}
+
+/// Unregisters whatever shortcuts are currently active and re-registers the
+/// ones enabled in `config`, so edits to the config take effect immediately
+/// without restarting the app.
+pub fn apply_hotkeys(app: &AppHandle, config: &HotkeysConfig) -> Result<(), String> {
+    let shortcuts = app.global_shortcut();
+    shortcuts.unregister_all().map_err(|e| e.to_string())?;
+
+    let mut registered: Vec<(String, Shortcut)> = Vec::new();
+    for (action, binding) in [
+        (ACTION_TOGGLE_WINDOW, &config.toggle_window),
+        (ACTION_TOGGLE_RUNNER, &config.toggle_runner),
+    ] {
+        if !binding.enabled {
+            continue;
+        }
+        let shortcut: Shortcut = binding
+            .keys
+            .parse()
+            .map_err(|e| format!("invalid shortcut \"{}\" for {}: {}", binding.keys, action, e))?;
+        shortcuts.register(shortcut.clone()).map_err(|e| e.to_string())?;
+        registered.push((action.to_string(), shortcut));
+    }
+
+    if let Some(state) = app.try_state::<HotkeysState>() {
+        if let Ok(mut guard) = state.registered.lock() {
+            *guard = registered;
+        }
+    }
+    Ok(())
+}
+
+/// Resolves a fired `Shortcut` back to the action name it was registered
+/// under, if any.
+pub fn action_for_shortcut(app: &AppHandle, shortcut: &Shortcut) -> Option<String> {
+    let state = app.try_state::<HotkeysState>()?;
+    let guard = state.registered.lock().ok()?;
+    guard
+        .iter()
+        .find(|(_, registered)| registered == shortcut)
+        .map(|(action, _)| action.clone())
+}
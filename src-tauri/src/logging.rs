@@ -0,0 +1,63 @@
+//! Structured tracing with daily-rotated file logs for sidecar/watchdog
+//! diagnostics.
+//!
+//! `println!` output vanishes once the app is built with
+//! `windows_subsystem = "windows"` (no console window in release), so this is
+//! the only way to see restart-loop diagnostics after the fact.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use tauri::{AppHandle, Manager};
+use tracing_appender::non_blocking::WorkerGuard;
+use tracing_subscriber::EnvFilter;
+
+const LOG_FILE_PREFIX: &str = "stocksbot";
+
+/// Initializes a daily-rotated file logger under the app's log directory.
+/// The returned guard must be kept alive for the lifetime of the process —
+/// dropping it stops the background flush thread, so callers should
+/// `app.manage(guard)` it.
+pub fn init_logging(app: &AppHandle) -> Option<WorkerGuard> {
+    let log_dir = app.path().app_log_dir().ok()?;
+    if fs::create_dir_all(&log_dir).is_err() {
+        return None;
+    }
+
+    let file_appender = tracing_appender::rolling::daily(&log_dir, LOG_FILE_PREFIX);
+    let (non_blocking, guard) = tracing_appender::non_blocking(file_appender);
+
+    tracing_subscriber::fmt()
+        .with_writer(non_blocking)
+        .with_ansi(false)
+        .with_env_filter(EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")))
+        .init();
+
+    println!("Structured logs writing to {}", log_dir.display());
+    Some(guard)
+}
+
+/// Tails the most recently modified log file in the app's log directory,
+/// returning up to `lines` of its trailing content.
+pub fn tail_recent_logs(app: &AppHandle, lines: usize) -> Result<Vec<String>, String> {
+    let log_dir = app.path().app_log_dir().map_err(|e| e.to_string())?;
+    let latest = latest_log_file(&log_dir).ok_or_else(|| "no log file found yet".to_string())?;
+    let contents = fs::read_to_string(&latest).map_err(|e| e.to_string())?;
+    let all_lines: Vec<&str> = contents.lines().collect();
+    let start = all_lines.len().saturating_sub(lines);
+    Ok(all_lines[start..].iter().map(|line| line.to_string()).collect())
+}
+
+fn latest_log_file(dir: &Path) -> Option<PathBuf> {
+    fs::read_dir(dir)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .map(|name| name.starts_with(LOG_FILE_PREFIX))
+                .unwrap_or(false)
+        })
+        .max_by_key(|path| fs::metadata(path).and_then(|m| m.modified()).ok())
+}
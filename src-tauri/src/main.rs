@@ -1,34 +1,33 @@
 // Prevents additional console window on Windows in release, DO NOT REMOVE!!
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
-use std::net::{SocketAddr, TcpStream};
 use std::path::PathBuf;
-use std::process::{Child, Command, Stdio};
+use std::process::Child;
 use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use std::sync::{Arc, Mutex};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use serde::{Deserialize, Serialize};
 use tauri::menu::{MenuBuilder, MenuItem, MenuItemBuilder, PredefinedMenuItem};
 use tauri::image::Image;
 use tauri::tray::TrayIconBuilder;
 use tauri::{AppHandle, Emitter, Manager};
 use tauri_plugin_notification::{NotificationExt, PermissionState};
-
-const KEYCHAIN_SERVICE: &str = "com.stocksbot.alpaca";
-
-#[derive(Debug, Serialize, Deserialize)]
-struct CredentialStatus {
-    paper_available: bool,
-    live_available: bool,
-}
-
-#[derive(Debug, Serialize, Deserialize)]
-struct AlpacaCredentials {
-    api_key: String,
-    secret_key: String,
-}
-
-#[derive(Debug, Clone, Deserialize)]
+use tracing::{error, info, instrument, warn};
+
+use stocksbot_core::credentials::{AlpacaCredentials, CredentialStatus};
+use stocksbot_core::health::is_backend_healthy;
+use stocksbot_core::sidecar::LaunchOutcome;
+
+mod backend_config;
+mod backoff;
+mod hotkeys;
+mod logging;
+mod metrics;
+mod persistence;
+mod time_ago;
+mod vault;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct TraySummaryPayload {
     runner_status: Option<String>,
     broker_connected: Option<bool>,
@@ -45,6 +44,9 @@ struct TraySummaryPayload {
     cash: Option<f64>,
     daily_pnl: Option<f64>,
     daily_pnl_pct: Option<f64>,
+    /// Fills since the last snapshot, if the backend reports them — recorded
+    /// to the trade log alongside the equity snapshot.
+    trades: Option<Vec<stocksbot_core::persistence::TradeLogEntry>>,
 }
 
 struct TrayState {
@@ -55,6 +57,9 @@ struct TrayState {
     toggle_runner_item: Mutex<Option<MenuItem<tauri::Wry>>>,
     runner_running: Mutex<bool>,
     last_snapshot: Mutex<String>,
+    /// Last payload received, re-rendered every tick so the "time ago" label
+    /// ages even when the frontend hasn't pushed a fresh snapshot.
+    last_payload: Mutex<Option<TraySummaryPayload>>,
 }
 
 struct SidecarState {
@@ -85,6 +90,7 @@ impl Default for TrayState {
             toggle_runner_item: Mutex::new(None),
             runner_running: Mutex::new(false),
             last_snapshot: Mutex::new("Status unavailable".to_string()),
+            last_payload: Mutex::new(None),
         }
     }
 }
@@ -121,6 +127,18 @@ fn hide_main_window(app: &AppHandle) {
     }
 }
 
+fn toggle_main_window(app: &AppHandle) {
+    let is_visible = app
+        .get_webview_window("main")
+        .and_then(|window| window.is_visible().ok())
+        .unwrap_or(false);
+    if is_visible {
+        hide_main_window(app);
+    } else {
+        show_main_window(app);
+    }
+}
+
 fn update_tray_status_ui(app: &AppHandle, payload: &TraySummaryPayload) -> Result<(), String> {
     let runner = sanitize_short(payload.runner_status.clone(), "unknown", 24).to_uppercase();
     let broker = if payload.broker_connected.unwrap_or(false) {
@@ -135,7 +153,11 @@ fn update_tray_status_ui(app: &AppHandle, payload: &TraySummaryPayload) -> Resul
     let active_jobs = payload.optimizer_active_jobs.unwrap_or(0);
     let queue_depth = payload.optimizer_queue_depth.unwrap_or(0);
     let stalled_jobs = payload.optimizer_stalled_jobs.unwrap_or(0);
-    let last_update = sanitize_short(payload.last_update.clone(), "-", 24);
+    let stale_after_secs = time_ago::current_tray_config(app).stale_after_secs;
+    let last_update = match &payload.last_update {
+        Some(raw) => time_ago::relative_time_ago(raw, stale_after_secs),
+        None => "-".to_string(),
+    };
     let snapshot = format!(
         "Runner: {} | Broker: {} | Poll Errors: {} | Open Positions: {} | Jobs: {} active / {} queued{} | Strategy: {} | Universe: {} | Updated: {}",
         runner,
@@ -224,6 +246,19 @@ fn update_tray_status_ui(app: &AppHandle, payload: &TraySummaryPayload) -> Resul
         if let Ok(mut guard) = state.last_snapshot.lock() {
             *guard = snapshot.clone();
         }
+        if let Ok(mut guard) = state.last_payload.lock() {
+            *guard = Some(payload.clone());
+        }
+    }
+
+    if let Some(metrics_state) = app.try_state::<Arc<metrics::MetricsState>>() {
+        metrics_state.set_tray_gauges(
+            poll_errors,
+            open_positions,
+            active_jobs,
+            payload.equity.unwrap_or(0.0),
+            payload.daily_pnl.unwrap_or(0.0),
+        );
     }
 
     if let Some(tray) = app.tray_by_id(TRAY_ID) {
@@ -240,49 +275,110 @@ fn update_tray_status_ui(app: &AppHandle, payload: &TraySummaryPayload) -> Resul
             last_update
         )));
     }
+
+    // Broadcast to any open window so the UI can subscribe to live updates
+    // instead of being the thing that pushes state down in the first place.
+    let _ = app.emit("tray-summary", payload.clone());
     Ok(())
 }
 
-const BACKEND_URL: &str = "http://127.0.0.1:8000";
-const BACKEND_ADDR: &str = "127.0.0.1:8000";
+/// Re-renders the tray's "time ago" label every few seconds so a window that
+/// never receives a new snapshot still visibly ages past the stale threshold.
+fn start_tray_age_ticker(app_handle: AppHandle) {
+    std::thread::Builder::new()
+        .name("tray-age-ticker".into())
+        .spawn(move || loop {
+            std::thread::sleep(Duration::from_secs(5));
+            let payload = match app_handle.try_state::<TrayState>() {
+                Some(state) => state.last_payload.lock().ok().and_then(|guard| guard.clone()),
+                None => None,
+            };
+            if let Some(payload) = payload {
+                let _ = update_tray_status_ui(&app_handle, &payload);
+            }
+        })
+        .expect("Failed to spawn tray age ticker thread");
+}
 
-/// Quick TCP-level reachability check (used before HTTP is available).
-fn is_backend_tcp_reachable() -> bool {
-    let addr: SocketAddr = match BACKEND_ADDR.parse() {
-        Ok(parsed) => parsed,
-        Err(_) => return false,
-    };
-    TcpStream::connect_timeout(&addr, Duration::from_millis(350)).is_ok()
-}
-
-/// Full HTTP health check — confirms the backend is responding to requests.
-fn is_backend_healthy() -> bool {
-    let agent = ureq::AgentBuilder::new()
-        .timeout_connect(Duration::from_secs(2))
-        .timeout(Duration::from_secs(5))
-        .build();
-    match agent.get(&format!("{}/status", BACKEND_URL)).call() {
-        Ok(resp) => resp.status() == 200,
-        Err(_) => false,
-    }
+/// Fetches and decodes the backend's full status payload, so the watchdog
+/// can refresh the tray itself instead of waiting on the frontend to call
+/// `update_tray_summary`.
+fn fetch_backend_status(base_url: &str) -> Option<TraySummaryPayload> {
+    let value = stocksbot_core::health::fetch_backend_status(base_url)?;
+    serde_json::from_value(value).ok()
 }
 
-/// Wait for the backend to become healthy after launch.
-/// Polls up to `max_attempts` times with `interval` between each attempt.
-fn wait_for_backend_ready(max_attempts: u32, interval: Duration) -> bool {
+/// Polls until the backend is healthy, logging the attempt number that
+/// succeeded (or a warning if none did). `stocksbot-core` intentionally
+/// carries no `tracing` dependency (the CLI doesn't need one), so the GUI
+/// wraps its bare poll loop here to keep the attempt-count logging this
+/// watchdog has always had.
+#[instrument(skip(interval), fields(max_attempts))]
+fn wait_for_backend_ready(base_url: &str, max_attempts: u32, interval: Duration) -> bool {
     for attempt in 1..=max_attempts {
-        if is_backend_healthy() {
-            println!("Backend healthy after {} attempt(s)", attempt);
+        if is_backend_healthy(base_url) {
+            info!(attempt, "backend healthy");
             return true;
         }
         std::thread::sleep(interval);
     }
     // Final attempt — maybe it just needs one more second
-    is_backend_healthy()
+    if is_backend_healthy(base_url) {
+        info!(attempt = max_attempts + 1, "backend healthy");
+        return true;
+    }
+    warn!(max_attempts, "backend did not become healthy within wait window");
+    false
+}
+
+fn emit_backend_health_event(
+    app_handle: &AppHandle,
+    status: &str,
+    restart_count: u32,
+    consecutive_failures: u32,
+    next_retry_in: Option<Duration>,
+) {
+    let event = backoff::BackendHealthEvent::new(status, restart_count, consecutive_failures, next_retry_in);
+    let _ = app_handle.emit("backend-health", event);
+}
+
+/// Records a tray snapshot (and any reported fills) to the history database,
+/// so the momentary tray view can be charted after the fact. A missing or
+/// disabled database is not an error — it just means history isn't kept.
+fn record_payload_to_history(app_handle: &AppHandle, payload: &TraySummaryPayload) {
+    let Some(state) = app_handle.try_state::<persistence::PersistenceState>() else {
+        return;
+    };
+    let recorded_at = chrono::Utc::now().timestamp();
+
+    // `/status` can return 200 with no account fields yet (e.g. runner not
+    // connected to the broker) — skip the snapshot rather than recording a
+    // spurious equity=$0 row that would pollute the charted equity curve.
+    if let Some(equity) = payload.equity {
+        let snapshot = stocksbot_core::persistence::EquitySnapshotRow {
+            recorded_at,
+            equity,
+            cash: payload.cash.unwrap_or(0.0),
+            daily_pnl: payload.daily_pnl.unwrap_or(0.0),
+            daily_pnl_pct: payload.daily_pnl_pct.unwrap_or(0.0),
+            open_positions: payload.open_positions.unwrap_or(0),
+        };
+        if let Err(e) = stocksbot_core::persistence::record_equity_snapshot(&state.0, recorded_at, &snapshot) {
+            warn!(error = %e, "failed to record equity snapshot");
+        }
+    }
+    if let Some(trades) = &payload.trades {
+        if !trades.is_empty() {
+            if let Err(e) = stocksbot_core::persistence::record_trades(&state.0, recorded_at, trades) {
+                warn!(error = %e, "failed to record trade log entries");
+            }
+        }
+    }
 }
 
 /// Background watchdog that monitors backend health and auto-restarts on failure.
 /// Runs in a dedicated thread. Emits `backend-health` events to the frontend.
+#[instrument(skip(app_handle))]
 fn start_backend_watchdog(app_handle: AppHandle) {
     let stop_flag = {
         let state = app_handle.state::<SidecarState>();
@@ -293,41 +389,106 @@ fn start_backend_watchdog(app_handle: AppHandle) {
         .name("backend-watchdog".into())
         .spawn(move || {
             let mut consecutive_failures: u32 = 0;
+            let mut consecutive_healthy: u32 = 0;
             let max_failures_before_restart: u32 = 3;
             let max_auto_restarts_per_session: u32 = 5;
+            // Gates restart *attempts* apart; re-armed with a fresh exponential
+            // delay after every attempt so repeated failures back off instead
+            // of hammering the sidecar on the fixed 10s poll cadence.
+            let mut next_retry_at: Option<Instant> = None;
+            // Retention is swept periodically rather than on every poll, since
+            // a `DELETE ... WHERE` pass every 10s would be pure overhead.
+            let mut last_retention_sweep = Instant::now();
+            let retention_sweep_interval = Duration::from_secs(3600);
 
             // Give the backend time to fully boot before first check
             std::thread::sleep(Duration::from_secs(15));
 
             loop {
                 if stop_flag.load(Ordering::Relaxed) {
-                    println!("Backend watchdog stopping");
+                    info!("backend watchdog stopping");
                     break;
                 }
 
-                if is_backend_healthy() {
+                let backend_base_url = backend_config::current_backend_config(&app_handle).base_url();
+                let status_payload = fetch_backend_status(&backend_base_url);
+                let healthy = status_payload.is_some();
+                if let Some(metrics_state) = app_handle.try_state::<Arc<metrics::MetricsState>>() {
+                    metrics_state.set_backend_up(healthy);
+                }
+                if let Some(payload) = &status_payload {
+                    let _ = update_tray_status_ui(&app_handle, payload);
+                    record_payload_to_history(&app_handle, payload);
+                }
+
+                if last_retention_sweep.elapsed() >= retention_sweep_interval {
+                    last_retention_sweep = Instant::now();
+                    if let Some(state) = app_handle.try_state::<persistence::PersistenceState>() {
+                        let retention_days = persistence::current_persistence_config(&app_handle).retention_days;
+                        let now = chrono::Utc::now().timestamp();
+                        if let Err(e) = stocksbot_core::persistence::enforce_retention(&state.0, now, retention_days) {
+                            warn!(error = %e, "failed to enforce history retention window");
+                        }
+                    }
+                }
+                let restart_count = app_handle
+                    .try_state::<SidecarState>()
+                    .map(|s| s.restart_count.load(Ordering::Relaxed))
+                    .unwrap_or(0);
+
+                if healthy {
                     if consecutive_failures > 0 {
-                        println!("Backend recovered after {} failure(s)", consecutive_failures);
-                        let _ = app_handle.emit("backend-health", "healthy");
+                        info!(consecutive_failures, "backend recovered");
+                        emit_backend_health_event(&app_handle, "healthy", restart_count, 0, None);
                     }
                     consecutive_failures = 0;
+                    next_retry_at = None;
+
+                    consecutive_healthy += 1;
+                    if consecutive_healthy >= backoff::HEALTHY_CHECKS_TO_RESET_BREAKER {
+                        consecutive_healthy = 0;
+                        if let Some(state) = app_handle.try_state::<SidecarState>() {
+                            let previous = state.restart_count.swap(0, Ordering::Relaxed);
+                            if previous > 0 {
+                                info!(previous_restart_count = previous, "circuit breaker reset after sustained health");
+                                // Only the breaker's resettable restart_count goes back to
+                                // zero here; stocksbot_backend_restarts_total stays monotonic.
+                            }
+                        }
+                    }
                 } else {
+                    consecutive_healthy = 0;
                     consecutive_failures += 1;
-                    println!(
-                        "Backend health check failed ({}/{})",
-                        consecutive_failures, max_failures_before_restart
+                    warn!(
+                        consecutive_failures,
+                        max_failures_before_restart, "backend health check failed"
                     );
 
                     if consecutive_failures >= max_failures_before_restart {
-                        let _ = app_handle.emit("backend-health", "unhealthy");
-
-                        let restart_count = {
-                            let state = app_handle.state::<SidecarState>();
-                            state.restart_count.load(Ordering::Relaxed)
-                        };
-
-                        if restart_count < max_auto_restarts_per_session {
-                            println!("Attempting backend auto-restart #{}", restart_count + 1);
+                        if backend_config::current_backend_config(&app_handle).external {
+                            warn!("external backend configured; not attempting a local sidecar restart");
+                            emit_backend_health_event(&app_handle, "unhealthy", restart_count, consecutive_failures, None);
+                        } else if restart_count >= max_auto_restarts_per_session {
+                            error!(
+                                restart_count,
+                                max_auto_restarts_per_session,
+                                "backend auto-restart limit reached; manual restart required"
+                            );
+                            emit_backend_health_event(&app_handle, "unhealthy", restart_count, consecutive_failures, None);
+                        } else if let Some(remaining) =
+                            next_retry_at.and_then(|at| at.checked_duration_since(Instant::now()))
+                        {
+                            info!(remaining_secs = remaining.as_secs(), "backend restart cooling down");
+                            emit_backend_health_event(
+                                &app_handle,
+                                "cooling_down",
+                                restart_count,
+                                consecutive_failures,
+                                Some(remaining),
+                            );
+                        } else {
+                            emit_backend_health_event(&app_handle, "unhealthy", restart_count, consecutive_failures, None);
+                            info!(attempt = restart_count + 1, "attempting backend auto-restart");
 
                             // Kill stale process if it exists
                             if let Some(state) = app_handle.try_state::<SidecarState>() {
@@ -348,34 +509,45 @@ fn start_backend_watchdog(app_handle: AppHandle) {
                             }
 
                             // Attempt re-launch
-                            if let Some(child) = launch_backend_sidecar(&app_handle) {
+                            let backend_config = backend_config::current_backend_config(&app_handle);
+                            let mut new_restart_count = restart_count;
+                            if let Some(child) = launch_backend_sidecar(&app_handle, &backend_config) {
                                 if let Some(state) = app_handle.try_state::<SidecarState>() {
                                     if let Ok(mut guard) = state.process.lock() {
                                         *guard = Some(child);
                                     }
-                                    state.restart_count.fetch_add(1, Ordering::Relaxed);
+                                    new_restart_count = state.restart_count.fetch_add(1, Ordering::Relaxed) + 1;
+                                    if let Some(metrics_state) = app_handle.try_state::<Arc<metrics::MetricsState>>() {
+                                        metrics_state.increment_restarts_total();
+                                    }
                                 }
 
                                 // Wait for the restarted backend to come up
-                                if wait_for_backend_ready(60, Duration::from_millis(500)) {
-                                    println!("Backend restarted successfully");
-                                    let _ = app_handle.emit("backend-health", "restarted");
+                                if wait_for_backend_ready(&backend_config.base_url(), 60, Duration::from_millis(500)) {
+                                    info!("backend restarted successfully");
+                                    emit_backend_health_event(&app_handle, "restarted", new_restart_count, 0, None);
                                     consecutive_failures = 0;
+                                    next_retry_at = None;
                                 } else {
-                                    println!("Backend restart: process launched but not healthy yet");
+                                    warn!("backend restart: process launched but not healthy yet");
                                 }
                             } else {
-                                println!("Backend restart failed: could not find binary or script");
+                                error!("backend restart failed: could not find binary or script");
                             }
-                        } else {
-                            println!(
-                                "Backend auto-restart limit reached ({}/{}). Manual restart required.",
-                                restart_count, max_auto_restarts_per_session
-                            );
+
+                            // Use the pre-increment restart_count (this is the Nth restart,
+                            // 0-indexed) so the schedule starts at base*2^0 = 1s, not 2s.
+                            let delay = backoff::next_restart_delay(restart_count);
+                            next_retry_at = Some(Instant::now() + delay);
+                            info!(delay_secs = delay.as_secs(), "next auto-restart attempt scheduled");
                         }
                     }
                 }
 
+                if let Some(metrics_state) = app_handle.try_state::<Arc<metrics::MetricsState>>() {
+                    metrics_state.set_consecutive_failures(consecutive_failures);
+                }
+
                 // Poll every 10 seconds
                 for _ in 0..10 {
                     if stop_flag.load(Ordering::Relaxed) {
@@ -388,126 +560,41 @@ fn start_backend_watchdog(app_handle: AppHandle) {
         .expect("Failed to spawn backend watchdog thread");
 }
 
-fn find_backend_script(app: &AppHandle) -> Option<PathBuf> {
-    let mut candidates: Vec<PathBuf> = Vec::new();
-    if let Ok(cwd) = std::env::current_dir() {
-        candidates.push(cwd.join("../backend/app.py"));
-        candidates.push(cwd.join("backend/app.py"));
-    }
-    if let Ok(resource_dir) = app.path().resource_dir() {
-        // Tauri bundles `../backend/app.py` as `_up_/backend/app.py`
-        candidates.push(resource_dir.join("_up_/backend/app.py"));
-        candidates.push(resource_dir.join("backend/app.py"));
-        candidates.push(resource_dir.join("app.py"));
-    }
-    for candidate in candidates {
-        if candidate.exists() && candidate.is_file() {
-            return Some(candidate);
-        }
-    }
-    None
+/// Directories to check for a bundled backend, after the development-layout
+/// paths `stocksbot_core::sidecar` tries first.
+fn backend_search_dirs(app: &AppHandle) -> Vec<PathBuf> {
+    app.path().resource_dir().into_iter().collect()
 }
 
-fn find_backend_binary(app: &AppHandle) -> Option<PathBuf> {
-    let mut candidates: Vec<PathBuf> = Vec::new();
-    if let Ok(cwd) = std::env::current_dir() {
-        candidates.push(cwd.join("../backend/dist/stocksbot-backend"));
-        candidates.push(cwd.join("../backend/dist/stocksbot-backend.exe"));
-        candidates.push(cwd.join("backend/dist/stocksbot-backend"));
-        candidates.push(cwd.join("backend/dist/stocksbot-backend.exe"));
-    }
-    if let Ok(resource_dir) = app.path().resource_dir() {
-        // Tauri bundles `../backend/dist/stocksbot-backend` as `_up_/backend/dist/stocksbot-backend`
-        candidates.push(resource_dir.join("_up_/backend/dist/stocksbot-backend"));
-        candidates.push(resource_dir.join("_up_/backend/dist/stocksbot-backend.exe"));
-        candidates.push(resource_dir.join("backend/dist/stocksbot-backend"));
-        candidates.push(resource_dir.join("backend/dist/stocksbot-backend.exe"));
-        candidates.push(resource_dir.join("stocksbot-backend"));
-        candidates.push(resource_dir.join("stocksbot-backend.exe"));
-    }
-    for candidate in candidates {
-        if candidate.exists() && candidate.is_file() {
-            return Some(candidate);
-        }
-    }
-    None
-}
-
-fn launch_backend_sidecar(app: &AppHandle) -> Option<Child> {
-    if is_backend_tcp_reachable() {
-        println!("Backend already reachable at {}; skipping sidecar launch.", BACKEND_ADDR);
-        return None;
-    }
-    if let Some(binary) = find_backend_binary(app) {
-        let mut cmd = Command::new(&binary);
-        if let Some(parent) = binary.parent() {
-            cmd.current_dir(parent);
-        }
-        cmd.stdin(Stdio::null());
-        cmd.stdout(Stdio::null());
-        cmd.stderr(Stdio::null());
-        match cmd.spawn() {
-            Ok(child) => {
-                println!("Launched backend sidecar binary {}", binary.display());
-                return Some(child);
-            }
-            Err(e) => {
-                println!(
-                    "Failed to launch backend sidecar binary {}: {}",
-                    binary.display(),
-                    e
-                );
-            }
+#[instrument(skip(app, config))]
+fn launch_backend_sidecar(app: &AppHandle, config: &backend_config::BackendConfig) -> Option<Child> {
+    match stocksbot_core::sidecar::launch_backend_sidecar(config, None, &backend_search_dirs(app)) {
+        LaunchOutcome::External => {
+            info!(addr = %config.addr(), "external backend configured; not spawning a local sidecar");
+            None
         }
-    }
-    let script = match find_backend_script(app) {
-        Some(path) => path,
-        None => {
-            println!("Backend sidecar script not found. Run backend manually if needed.");
-            return None;
+        LaunchOutcome::AlreadyReachable => {
+            info!(addr = %config.addr(), "backend already reachable; skipping sidecar launch");
+            None
         }
-    };
-
-    let mut last_error = String::new();
-    for interpreter in ["python3", "python"] {
-        let mut cmd = Command::new(interpreter);
-        cmd.arg(&script);
-        if let Some(parent) = script.parent() {
-            cmd.current_dir(parent);
+        LaunchOutcome::Spawned(child) => {
+            info!(pid = child.id(), "launched backend sidecar");
+            Some(child)
         }
-        cmd.stdin(Stdio::null());
-        cmd.stdout(Stdio::null());
-        cmd.stderr(Stdio::null());
-        match cmd.spawn() {
-            Ok(child) => {
-                println!(
-                    "Launched backend sidecar using {} {}",
-                    interpreter,
-                    script.display()
-                );
-                return Some(child);
-            }
-            Err(e) => {
-                last_error = e.to_string();
-            }
+        LaunchOutcome::NotFound => {
+            warn!("backend sidecar binary/script not found; run backend manually if needed");
+            None
         }
     }
-
-    println!(
-        "Failed to launch backend sidecar for {}: {}",
-        script.display(),
-        last_error
-    );
-    None
 }
 
+#[instrument(skip(app))]
 fn stop_backend_sidecar(app: &AppHandle) {
     if let Some(state) = app.try_state::<SidecarState>() {
         if let Ok(mut guard) = state.process.lock() {
             if let Some(mut child) = guard.take() {
-                let _ = child.kill();
-                let _ = child.wait();
-                println!("Stopped backend sidecar process");
+                stocksbot_core::sidecar::stop_sidecar(&mut child);
+                info!("stopped backend sidecar process");
             }
         }
     }
@@ -586,104 +673,107 @@ fn request_notification_permission(app: tauri::AppHandle) -> Result<String, Stri
     Ok(value.to_string())
 }
 
-fn credential_username(mode: &str, field: &str) -> String {
-    format!("{}_{}", mode, field)
+#[tauri::command]
+fn save_alpaca_credentials(mode: String, api_key: String, secret_key: String) -> Result<(), String> {
+    stocksbot_core::credentials::save_credentials(&mode, &api_key, &secret_key)
 }
 
-fn validate_key_material(value: &str, field: &str) -> Result<String, String> {
-    let trimmed = value.trim();
-    if trimmed.is_empty() {
-        return Err(format!("{} is required", field));
-    }
-    if trimmed.len() < 8 {
-        return Err(format!("{} appears too short", field));
-    }
-    if trimmed.len() > 512 {
-        return Err(format!("{} is too long", field));
-    }
-    if trimmed.chars().any(|c| c.is_whitespace()) {
-        return Err(format!("{} cannot contain whitespace", field));
-    }
-    Ok(trimmed.to_string())
+#[tauri::command]
+fn get_alpaca_credentials(mode: String) -> Result<Option<AlpacaCredentials>, String> {
+    stocksbot_core::credentials::get_credentials(&mode)
 }
 
 #[tauri::command]
-fn save_alpaca_credentials(mode: String, api_key: String, secret_key: String) -> Result<(), String> {
-    let normalized_mode = mode.trim().to_lowercase();
-    if normalized_mode != "paper" && normalized_mode != "live" {
-        return Err("mode must be paper or live".to_string());
-    }
-    let sanitized_api_key = validate_key_material(&api_key, "api_key")?;
-    let sanitized_secret_key = validate_key_material(&secret_key, "secret_key")?;
-
-    let api_entry = keyring::Entry::new(KEYCHAIN_SERVICE, &credential_username(&normalized_mode, "api_key"))
-        .map_err(|e| e.to_string())?;
-    let secret_entry = keyring::Entry::new(KEYCHAIN_SERVICE, &credential_username(&normalized_mode, "secret_key"))
-        .map_err(|e| e.to_string())?;
+fn get_alpaca_credentials_status() -> Result<CredentialStatus, String> {
+    stocksbot_core::credentials::get_credentials_status()
+}
 
-    api_entry.set_password(&sanitized_api_key).map_err(|e| e.to_string())?;
-    secret_entry.set_password(&sanitized_secret_key).map_err(|e| e.to_string())?;
-    Ok(())
+#[tauri::command]
+fn clear_alpaca_credentials(mode: String) -> Result<(), String> {
+    stocksbot_core::credentials::clear_credentials(&mode)
 }
 
+/// Sealed-at-rest counterpart to `save_alpaca_credentials`. Opt-in: existing
+/// plaintext-keyring users are unaffected unless they explicitly call this.
 #[tauri::command]
-fn get_alpaca_credentials(mode: String) -> Result<Option<AlpacaCredentials>, String> {
-    let normalized_mode = mode.trim().to_lowercase();
-    if normalized_mode != "paper" && normalized_mode != "live" {
-        return Err("mode must be paper or live".to_string());
+fn save_alpaca_credentials_vaulted(
+    mode: String,
+    api_key: String,
+    secret_key: String,
+    passphrase: String,
+) -> Result<(), String> {
+    let normalized_mode = stocksbot_core::credentials::validate_mode(&mode)?;
+    let sanitized_api_key = stocksbot_core::credentials::validate_key_material(&api_key, "api_key")?;
+    let sanitized_secret_key = stocksbot_core::credentials::validate_key_material(&secret_key, "secret_key")?;
+    if passphrase.is_empty() {
+        return Err("passphrase is required".to_string());
     }
 
-    let api_entry = keyring::Entry::new(KEYCHAIN_SERVICE, &credential_username(&normalized_mode, "api_key"))
-        .map_err(|e| e.to_string())?;
-    let secret_entry = keyring::Entry::new(KEYCHAIN_SERVICE, &credential_username(&normalized_mode, "secret_key"))
-        .map_err(|e| e.to_string())?;
+    let payload = serde_json::json!({
+        "api_key": sanitized_api_key,
+        "secret_key": sanitized_secret_key,
+    });
+    let sealed = vault::seal(passphrase.as_bytes(), payload.to_string().as_bytes())?;
 
-    let api_key = match api_entry.get_password() {
+    let entry = stocksbot_core::credentials::keyring_entry(&normalized_mode, "vault")?;
+    entry.set_password(&sealed).map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+#[tauri::command]
+fn get_alpaca_credentials_vaulted(
+    app: tauri::AppHandle,
+    mode: String,
+    passphrase: Option<String>,
+) -> Result<Option<AlpacaCredentials>, String> {
+    let normalized_mode = stocksbot_core::credentials::validate_mode(&mode)?;
+
+    let entry = stocksbot_core::credentials::keyring_entry(&normalized_mode, "vault")?;
+    let sealed = match entry.get_password() {
         Ok(value) => value,
         Err(_) => return Ok(None),
     };
-    let secret_key = match secret_entry.get_password() {
-        Ok(value) => value,
-        Err(_) => return Ok(None),
+
+    let vault_state = app.try_state::<vault::VaultState>();
+    let supplied = passphrase.is_some();
+    let passphrase_bytes = match passphrase {
+        Some(p) => p.into_bytes(),
+        None => vault_state
+            .as_deref()
+            .and_then(|state| state.cached_passphrase())
+            .ok_or_else(|| "vault is locked; unlock it or provide a passphrase".to_string())?,
     };
 
+    let plaintext = vault::unseal(&passphrase_bytes, &sealed)?;
+    // Only cache a freshly-supplied passphrase, and only now that `unseal`
+    // has proven it correct — caching it up front would lock a mistyped
+    // passphrase in for the full timeout, breaking every subsequent
+    // cache-based call until it expired.
+    if supplied {
+        if let Some(state) = &vault_state {
+            state.unlock(passphrase_bytes, None);
+        }
+    }
+    let payload: serde_json::Value = serde_json::from_slice(&plaintext).map_err(|e| e.to_string())?;
+    let api_key = payload.get("api_key").and_then(|v| v.as_str()).unwrap_or_default().to_string();
+    let secret_key = payload.get("secret_key").and_then(|v| v.as_str()).unwrap_or_default().to_string();
     Ok(Some(AlpacaCredentials { api_key, secret_key }))
 }
 
 #[tauri::command]
-fn get_alpaca_credentials_status() -> Result<CredentialStatus, String> {
-    let paper_api = keyring::Entry::new(KEYCHAIN_SERVICE, &credential_username("paper", "api_key"))
-        .map_err(|e| e.to_string())?;
-    let paper_secret = keyring::Entry::new(KEYCHAIN_SERVICE, &credential_username("paper", "secret_key"))
-        .map_err(|e| e.to_string())?;
-    let live_api = keyring::Entry::new(KEYCHAIN_SERVICE, &credential_username("live", "api_key"))
-        .map_err(|e| e.to_string())?;
-    let live_secret = keyring::Entry::new(KEYCHAIN_SERVICE, &credential_username("live", "secret_key"))
-        .map_err(|e| e.to_string())?;
-
-    let paper_available = paper_api.get_password().is_ok() && paper_secret.get_password().is_ok();
-    let live_available = live_api.get_password().is_ok() && live_secret.get_password().is_ok();
-
-    Ok(CredentialStatus {
-        paper_available,
-        live_available,
-    })
+fn unlock_vault(app: tauri::AppHandle, passphrase: String, timeout_secs: Option<u64>) -> Result<(), String> {
+    let state = app
+        .try_state::<vault::VaultState>()
+        .ok_or_else(|| "vault not initialized".to_string())?;
+    state.unlock(passphrase.into_bytes(), timeout_secs.map(Duration::from_secs));
+    Ok(())
 }
 
 #[tauri::command]
-fn clear_alpaca_credentials(mode: String) -> Result<(), String> {
-    let normalized_mode = mode.trim().to_lowercase();
-    if normalized_mode != "paper" && normalized_mode != "live" {
-        return Err("mode must be paper or live".to_string());
+fn lock_vault(app: tauri::AppHandle) -> Result<(), String> {
+    if let Some(state) = app.try_state::<vault::VaultState>() {
+        state.lock();
     }
-
-    let api_entry = keyring::Entry::new(KEYCHAIN_SERVICE, &credential_username(&normalized_mode, "api_key"))
-        .map_err(|e| e.to_string())?;
-    let secret_entry = keyring::Entry::new(KEYCHAIN_SERVICE, &credential_username(&normalized_mode, "secret_key"))
-        .map_err(|e| e.to_string())?;
-
-    let _ = api_entry.delete_password();
-    let _ = secret_entry.delete_password();
     Ok(())
 }
 
@@ -698,9 +788,41 @@ struct BackendHealthStatus {
     restart_count: u32,
 }
 
+#[tauri::command]
+fn get_recent_logs(app: tauri::AppHandle, lines: usize) -> Result<Vec<String>, String> {
+    logging::tail_recent_logs(&app, lines)
+}
+
+#[tauri::command]
+fn get_hotkeys(app: tauri::AppHandle) -> Result<hotkeys::HotkeysConfig, String> {
+    Ok(hotkeys::load_hotkeys_config(&app))
+}
+
+#[tauri::command]
+fn set_hotkeys(app: tauri::AppHandle, config: hotkeys::HotkeysConfig) -> Result<(), String> {
+    hotkeys::save_hotkeys_config(&app, &config)?;
+    hotkeys::apply_hotkeys(&app, &config)
+}
+
+#[tauri::command]
+fn get_backend_config(app: tauri::AppHandle) -> Result<backend_config::BackendConfig, String> {
+    Ok(backend_config::current_backend_config(&app))
+}
+
+#[tauri::command]
+fn set_backend_config(app: tauri::AppHandle, config: backend_config::BackendConfig) -> Result<(), String> {
+    backend_config::save_backend_config(&app, &config)?;
+    if let Some(state) = app.try_state::<backend_config::BackendConfigState>() {
+        if let Ok(mut guard) = state.0.lock() {
+            *guard = config;
+        }
+    }
+    Ok(())
+}
+
 #[tauri::command]
 fn check_backend_health(app: tauri::AppHandle) -> Result<BackendHealthStatus, String> {
-    let healthy = is_backend_healthy();
+    let healthy = is_backend_healthy(&backend_config::current_backend_config(&app).base_url());
     let restart_count = app
         .try_state::<SidecarState>()
         .map(|s| s.restart_count.load(Ordering::Relaxed))
@@ -715,12 +837,59 @@ fn main() {
     tauri::Builder::default()
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_notification::init())
+        .plugin(
+            tauri_plugin_global_shortcut::Builder::new()
+                .with_handler(|app, shortcut, event| {
+                    if event.state() != tauri_plugin_global_shortcut::ShortcutState::Pressed {
+                        return;
+                    }
+                    match hotkeys::action_for_shortcut(app, shortcut).as_deref() {
+                        Some(hotkeys::ACTION_TOGGLE_WINDOW) => toggle_main_window(app),
+                        Some(hotkeys::ACTION_TOGGLE_RUNNER) => {
+                            let _ = app.emit("tray-toggle-runner", "toggle");
+                        }
+                        _ => {}
+                    }
+                })
+                .build(),
+        )
         .setup(|app| {
-            println!("StocksBot is starting...");
+            if let Some(log_guard) = logging::init_logging(&app.handle()) {
+                app.manage(log_guard);
+            }
+            info!("StocksBot is starting...");
             app.manage(SidecarState::default());
 
+            let metrics_state = Arc::new(metrics::MetricsState::default());
+            let metrics_config = metrics::load_metrics_config(&app.handle());
+            if metrics_config.enabled {
+                metrics::start_metrics_server(metrics_state.clone(), metrics_config.port);
+            } else {
+                println!("Metrics endpoint disabled via metrics.json");
+            }
+            app.manage(metrics_state);
+
+            let backend_config = backend_config::load_backend_config(&app.handle());
+            app.manage(backend_config::BackendConfigState(Mutex::new(backend_config.clone())));
+
+            let tray_config = time_ago::load_tray_config(&app.handle());
+            app.manage(time_ago::TrayConfigState(Mutex::new(tray_config)));
+
+            let persistence_config = persistence::load_persistence_config(&app.handle());
+            app.manage(persistence::PersistenceConfigState(Mutex::new(persistence_config.clone())));
+            if persistence_config.enabled {
+                match persistence::init_history_pool(&app.handle()) {
+                    Ok(pool) => {
+                        app.manage(persistence::PersistenceState(pool));
+                    }
+                    Err(e) => warn!(error = %e, "failed to initialize history database; equity/trade history disabled"),
+                }
+            } else {
+                println!("History database disabled via persistence.json");
+            }
+
             let app_handle = app.handle().clone();
-            if let Some(child) = launch_backend_sidecar(&app_handle) {
+            if let Some(child) = launch_backend_sidecar(&app_handle, &backend_config) {
                 let sidecar_state = app.state::<SidecarState>();
                 if let Ok(mut guard) = sidecar_state.process.lock() {
                     *guard = Some(child);
@@ -729,13 +898,13 @@ fn main() {
                 // Wait for the backend to become fully healthy (up to 30s)
                 // PyInstaller one-file binaries need ~16s to extract + boot
                 println!("Waiting for backend to become healthy...");
-                if wait_for_backend_ready(60, Duration::from_millis(500)) {
+                if wait_for_backend_ready(&backend_config.base_url(), 60, Duration::from_millis(500)) {
                     println!("Backend is healthy and ready");
                 } else {
                     println!("Warning: backend launched but not responding to health checks yet");
                 }
-            } else if is_backend_healthy() {
-                println!("External backend already healthy at {}", BACKEND_ADDR);
+            } else if is_backend_healthy(&backend_config.base_url()) {
+                println!("External backend already healthy at {}", backend_config.addr());
             } else {
                 println!("Note: backend is not running. Start it manually: cd backend && python app.py");
             }
@@ -744,6 +913,15 @@ fn main() {
             start_backend_watchdog(app_handle.clone());
 
             app.manage(TrayState::default());
+            start_tray_age_ticker(app_handle.clone());
+
+            app.manage(vault::VaultState::default());
+
+            app.manage(hotkeys::HotkeysState::default());
+            let hotkeys_config = hotkeys::load_hotkeys_config(&app_handle);
+            if let Err(e) = hotkeys::apply_hotkeys(&app_handle, &hotkeys_config) {
+                warn!(error = %e, "failed to register global hotkeys");
+            }
 
             let runner_item = MenuItemBuilder::new("Runner: STARTING")
                 .enabled(false)
@@ -850,7 +1028,22 @@ fn main() {
             get_alpaca_credentials_status,
             clear_alpaca_credentials,
             update_tray_summary,
-            check_backend_health
+            check_backend_health,
+            get_recent_logs,
+            get_hotkeys,
+            set_hotkeys,
+            get_backend_config,
+            set_backend_config,
+            save_alpaca_credentials_vaulted,
+            get_alpaca_credentials_vaulted,
+            unlock_vault,
+            lock_vault,
+            persistence::query_equity_history,
+            persistence::query_trade_log,
+            persistence::get_persistence_config,
+            persistence::set_persistence_config,
+            time_ago::get_tray_config,
+            time_ago::set_tray_config
         ])
         .build(tauri::generate_context!())
         .expect("error while building tauri application")
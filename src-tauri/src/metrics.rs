@@ -0,0 +1,195 @@
+//! Minimal in-process Prometheus exposition endpoint for sidecar/watchdog health.
+//!
+//! This intentionally avoids pulling in a full HTTP stack: the watchdog and
+//! tray code only need to publish a handful of gauges/counters, and a scraper
+//! only ever issues a bare `GET /metrics`. A tiny line-based TCP server keeps
+//! the dependency footprint (and the risk of blocking the watchdog) small.
+
+use std::fs;
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+
+const METRICS_CONFIG_FILE: &str = "metrics.json";
+const DEFAULT_METRICS_PORT: u16 = 9753;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricsConfig {
+    pub enabled: bool,
+    pub port: u16,
+}
+
+impl Default for MetricsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            port: DEFAULT_METRICS_PORT,
+        }
+    }
+}
+
+fn metrics_config_path(app: &AppHandle) -> Option<PathBuf> {
+    app.path().app_config_dir().ok().map(|dir| dir.join(METRICS_CONFIG_FILE))
+}
+
+/// Loads the metrics feature-flag/port from disk, falling back to defaults
+/// (enabled, default port) if the file is missing or unreadable.
+pub fn load_metrics_config(app: &AppHandle) -> MetricsConfig {
+    let Some(path) = metrics_config_path(app) else {
+        return MetricsConfig::default();
+    };
+    match fs::read_to_string(&path) {
+        Ok(contents) => serde_json::from_str(&contents).unwrap_or_default(),
+        Err(_) => MetricsConfig::default(),
+    }
+}
+
+/// Gauges/counters live behind atomics so the watchdog thread and the
+/// metrics-server thread never contend on a lock.
+#[derive(Default)]
+pub struct MetricsState {
+    backend_up: AtomicBool,
+    backend_restarts_total: AtomicU64,
+    backend_consecutive_failures: AtomicU64,
+    poll_errors: AtomicU64,
+    open_positions: AtomicU64,
+    optimizer_active_jobs: AtomicU64,
+    equity_bits: AtomicU64,
+    daily_pnl_bits: AtomicU64,
+}
+
+impl MetricsState {
+    pub fn set_backend_up(&self, up: bool) {
+        self.backend_up.store(up, Ordering::Relaxed);
+    }
+
+    /// Bumps the lifetime restart counter. This is intentionally one-way:
+    /// the circuit breaker may reset the *resettable* `restart_count` it
+    /// gates on, but `_total` counters must stay monotonic or Prometheus
+    /// `rate()`/`increase()` reads a bogus drop instead of a restart.
+    pub fn increment_restarts_total(&self) {
+        self.backend_restarts_total.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn set_consecutive_failures(&self, failures: u32) {
+        self.backend_consecutive_failures.store(failures as u64, Ordering::Relaxed);
+    }
+
+    pub fn set_tray_gauges(
+        &self,
+        poll_errors: u64,
+        open_positions: u64,
+        optimizer_active_jobs: u64,
+        equity: f64,
+        daily_pnl: f64,
+    ) {
+        self.poll_errors.store(poll_errors, Ordering::Relaxed);
+        self.open_positions.store(open_positions, Ordering::Relaxed);
+        self.optimizer_active_jobs
+            .store(optimizer_active_jobs, Ordering::Relaxed);
+        self.equity_bits.store(equity.to_bits(), Ordering::Relaxed);
+        self.daily_pnl_bits.store(daily_pnl.to_bits(), Ordering::Relaxed);
+    }
+
+    fn render(&self) -> String {
+        let equity = f64::from_bits(self.equity_bits.load(Ordering::Relaxed));
+        let daily_pnl = f64::from_bits(self.daily_pnl_bits.load(Ordering::Relaxed));
+        let mut out = String::new();
+
+        out.push_str("# HELP stocksbot_backend_up Whether the last backend health check succeeded.\n");
+        out.push_str("# TYPE stocksbot_backend_up gauge\n");
+        out.push_str(&format!(
+            "stocksbot_backend_up {}\n",
+            self.backend_up.load(Ordering::Relaxed) as u8
+        ));
+
+        out.push_str("# HELP stocksbot_backend_restarts_total Total watchdog auto-restarts this session.\n");
+        out.push_str("# TYPE stocksbot_backend_restarts_total counter\n");
+        out.push_str(&format!(
+            "stocksbot_backend_restarts_total {}\n",
+            self.backend_restarts_total.load(Ordering::Relaxed)
+        ));
+
+        out.push_str("# HELP stocksbot_backend_consecutive_failures Consecutive failed health checks.\n");
+        out.push_str("# TYPE stocksbot_backend_consecutive_failures gauge\n");
+        out.push_str(&format!(
+            "stocksbot_backend_consecutive_failures {}\n",
+            self.backend_consecutive_failures.load(Ordering::Relaxed)
+        ));
+
+        out.push_str("# HELP stocksbot_poll_errors Poll errors reported by the runner.\n");
+        out.push_str("# TYPE stocksbot_poll_errors gauge\n");
+        out.push_str(&format!("stocksbot_poll_errors {}\n", self.poll_errors.load(Ordering::Relaxed)));
+
+        out.push_str("# HELP stocksbot_open_positions Open broker positions reported by the runner.\n");
+        out.push_str("# TYPE stocksbot_open_positions gauge\n");
+        out.push_str(&format!(
+            "stocksbot_open_positions {}\n",
+            self.open_positions.load(Ordering::Relaxed)
+        ));
+
+        out.push_str("# HELP stocksbot_optimizer_active_jobs Active optimizer jobs reported by the runner.\n");
+        out.push_str("# TYPE stocksbot_optimizer_active_jobs gauge\n");
+        out.push_str(&format!(
+            "stocksbot_optimizer_active_jobs {}\n",
+            self.optimizer_active_jobs.load(Ordering::Relaxed)
+        ));
+
+        out.push_str("# HELP stocksbot_equity Account equity reported by the runner.\n");
+        out.push_str("# TYPE stocksbot_equity gauge\n");
+        out.push_str(&format!("stocksbot_equity {}\n", equity));
+
+        out.push_str("# HELP stocksbot_daily_pnl Daily profit/loss reported by the runner.\n");
+        out.push_str("# TYPE stocksbot_daily_pnl gauge\n");
+        out.push_str(&format!("stocksbot_daily_pnl {}\n", daily_pnl));
+
+        out
+    }
+}
+
+fn handle_connection(mut stream: TcpStream, state: &MetricsState) {
+    // We don't care what was requested — the only route this server serves
+    // is `GET /metrics` — but we still have to drain the request so the
+    // client's write doesn't stall on a full socket buffer.
+    let mut buf = [0u8; 1024];
+    let _ = stream.read(&mut buf);
+
+    let body = state.render();
+    let response = format!(
+        "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        body.len(),
+        body
+    );
+    let _ = stream.write_all(response.as_bytes());
+}
+
+/// Starts the `/metrics` listener on a dedicated thread. Binding failures are
+/// logged and swallowed — a stuck or unavailable port must never take down
+/// the watchdog or the rest of the app.
+pub fn start_metrics_server(state: Arc<MetricsState>, port: u16) {
+    std::thread::Builder::new()
+        .name("metrics-server".into())
+        .spawn(move || {
+            let bind_addr = format!("127.0.0.1:{}", port);
+            let listener = match TcpListener::bind(&bind_addr) {
+                Ok(listener) => listener,
+                Err(e) => {
+                    println!("Metrics server failed to bind {}: {}", bind_addr, e);
+                    return;
+                }
+            };
+            println!("Metrics server listening on http://{}/metrics", bind_addr);
+            for stream in listener.incoming() {
+                match stream {
+                    Ok(stream) => handle_connection(stream, &state),
+                    Err(_) => continue,
+                }
+            }
+        })
+        .expect("Failed to spawn metrics server thread");
+}
@@ -0,0 +1,99 @@
+//! GUI-side wiring for the shared `stocksbot_core::persistence` history
+//! store: config persistence, app-state management, and the
+//! `query_equity_history` / `query_trade_log` commands.
+
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use tauri::{AppHandle, Manager};
+
+use stocksbot_core::persistence::{self, EquitySnapshotRow, Pool, TradeLogRow};
+
+pub use stocksbot_core::persistence::PersistenceConfig;
+
+const PERSISTENCE_CONFIG_FILE: &str = "persistence.json";
+const HISTORY_DB_FILE: &str = "history.sqlite3";
+
+/// Holds the pooled connection the watchdog thread and command handlers
+/// share, so reads/writes never block behind a single connection.
+pub struct PersistenceState(pub Pool);
+
+/// Holds the persistence config that's actually in effect, so the watchdog's
+/// retention sweep always uses the current `retention_days` even after
+/// `set_persistence_config` updates it — same pattern as `BackendConfigState`.
+#[derive(Default)]
+pub struct PersistenceConfigState(pub Mutex<PersistenceConfig>);
+
+impl PersistenceConfigState {
+    pub fn current(&self) -> PersistenceConfig {
+        self.0.lock().map(|guard| guard.clone()).unwrap_or_default()
+    }
+}
+
+pub fn current_persistence_config(app: &AppHandle) -> PersistenceConfig {
+    app.try_state::<PersistenceConfigState>()
+        .map(|state| state.current())
+        .unwrap_or_default()
+}
+
+fn persistence_config_path(app: &AppHandle) -> Option<PathBuf> {
+    app.path().app_config_dir().ok().map(|dir| dir.join(PERSISTENCE_CONFIG_FILE))
+}
+
+pub fn load_persistence_config(app: &AppHandle) -> PersistenceConfig {
+    let Some(path) = persistence_config_path(app) else {
+        return PersistenceConfig::default();
+    };
+    match fs::read_to_string(&path) {
+        Ok(contents) => serde_json::from_str(&contents).unwrap_or_default(),
+        Err(_) => PersistenceConfig::default(),
+    }
+}
+
+pub fn save_persistence_config(app: &AppHandle, config: &PersistenceConfig) -> Result<(), String> {
+    let path = persistence_config_path(app).ok_or_else(|| "could not resolve app config dir".to_string())?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    let contents = serde_json::to_string_pretty(config).map_err(|e| e.to_string())?;
+    fs::write(&path, contents).map_err(|e| e.to_string())
+}
+
+/// Opens the pooled history database under the app's data directory.
+pub fn init_history_pool(app: &AppHandle) -> Result<Pool, String> {
+    let dir = app.path().app_data_dir().map_err(|e| e.to_string())?;
+    persistence::init_pool(&dir.join(HISTORY_DB_FILE))
+}
+
+#[tauri::command]
+pub fn get_persistence_config(app: AppHandle) -> Result<PersistenceConfig, String> {
+    Ok(current_persistence_config(&app))
+}
+
+#[tauri::command]
+pub fn set_persistence_config(app: AppHandle, config: PersistenceConfig) -> Result<(), String> {
+    save_persistence_config(&app, &config)?;
+    if let Some(state) = app.try_state::<PersistenceConfigState>() {
+        if let Ok(mut guard) = state.0.lock() {
+            *guard = config;
+        }
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub fn query_equity_history(app: AppHandle, from: i64, to: i64) -> Result<Vec<EquitySnapshotRow>, String> {
+    let state = app
+        .try_state::<PersistenceState>()
+        .ok_or_else(|| "history database not initialized".to_string())?;
+    persistence::query_equity_history(&state.0, from, to)
+}
+
+#[tauri::command]
+pub fn query_trade_log(app: AppHandle, from: i64, to: i64) -> Result<Vec<TradeLogRow>, String> {
+    let state = app
+        .try_state::<PersistenceState>()
+        .ok_or_else(|| "history database not initialized".to_string())?;
+    persistence::query_trade_log(&state.0, from, to)
+}
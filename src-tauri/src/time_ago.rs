@@ -0,0 +1,112 @@
+//! Humanized "time ago" rendering for tray timestamps, plus the persisted
+//! staleness threshold that drives it (same config-file pattern as
+//! `backend_config`/`persistence`).
+
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+
+const TRAY_CONFIG_FILE: &str = "tray.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrayConfig {
+    /// Threshold past which a timestamp is flagged as stale in the rendered label.
+    pub stale_after_secs: i64,
+}
+
+impl Default for TrayConfig {
+    fn default() -> Self {
+        Self { stale_after_secs: 120 }
+    }
+}
+
+/// Holds the tray config that's actually in effect, so the tray label always
+/// reflects the current `stale_after_secs` even after `set_tray_config`
+/// updates it — same pattern as `BackendConfigState`.
+#[derive(Default)]
+pub struct TrayConfigState(pub Mutex<TrayConfig>);
+
+impl TrayConfigState {
+    pub fn current(&self) -> TrayConfig {
+        self.0.lock().map(|guard| guard.clone()).unwrap_or_default()
+    }
+}
+
+pub fn current_tray_config(app: &AppHandle) -> TrayConfig {
+    app.try_state::<TrayConfigState>()
+        .map(|state| state.current())
+        .unwrap_or_default()
+}
+
+fn tray_config_path(app: &AppHandle) -> Option<PathBuf> {
+    app.path().app_config_dir().ok().map(|dir| dir.join(TRAY_CONFIG_FILE))
+}
+
+pub fn load_tray_config(app: &AppHandle) -> TrayConfig {
+    let Some(path) = tray_config_path(app) else {
+        return TrayConfig::default();
+    };
+    match fs::read_to_string(&path) {
+        Ok(contents) => serde_json::from_str(&contents).unwrap_or_default(),
+        Err(_) => TrayConfig::default(),
+    }
+}
+
+pub fn save_tray_config(app: &AppHandle, config: &TrayConfig) -> Result<(), String> {
+    let path = tray_config_path(app).ok_or_else(|| "could not resolve app config dir".to_string())?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    let contents = serde_json::to_string_pretty(config).map_err(|e| e.to_string())?;
+    fs::write(&path, contents).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn get_tray_config(app: AppHandle) -> Result<TrayConfig, String> {
+    Ok(current_tray_config(&app))
+}
+
+#[tauri::command]
+pub fn set_tray_config(app: AppHandle, config: TrayConfig) -> Result<(), String> {
+    save_tray_config(&app, &config)?;
+    if let Some(state) = app.try_state::<TrayConfigState>() {
+        if let Ok(mut guard) = state.0.lock() {
+            *guard = config;
+        }
+    }
+    Ok(())
+}
+
+/// Parses `raw` as an RFC3339/ISO-8601 instant and renders it as a relative
+/// duration ("just now", "12s ago", "3m ago", "stale — 2h ago"). Falls back to
+/// the raw string verbatim if it doesn't parse. `stale_after_secs` is the
+/// user-configurable staleness threshold (see `TrayConfig`).
+pub fn relative_time_ago(raw: &str, stale_after_secs: i64) -> String {
+    let Ok(parsed) = DateTime::parse_from_rfc3339(raw) else {
+        return raw.to_string();
+    };
+    let elapsed = Utc::now().signed_duration_since(parsed.with_timezone(&Utc));
+    let secs = elapsed.num_seconds().max(0);
+
+    let label = if secs < 5 {
+        "just now".to_string()
+    } else if secs < 60 {
+        format!("{}s ago", secs)
+    } else if secs < 3600 {
+        format!("{}m ago", secs / 60)
+    } else if secs < 86400 {
+        format!("{}h ago", secs / 3600)
+    } else {
+        format!("{}d ago", secs / 86400)
+    };
+
+    if secs > stale_after_secs {
+        format!("stale — {}", label)
+    } else {
+        label
+    }
+}
@@ -0,0 +1,118 @@
+//! Optional master-passphrase vault layer over the OS keyring.
+//!
+//! Plaintext keyring storage (`save_alpaca_credentials`/`get_alpaca_credentials`)
+//! remains the default so existing users aren't broken. This module adds an
+//! opt-in path that seals secrets with a passphrase-derived key (Argon2id ->
+//! XChaCha20-Poly1305) before they ever touch the keyring.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use argon2::Argon2;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use chacha20poly1305::aead::{Aead, KeyInit};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+use rand::rngs::OsRng;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 24;
+const DEFAULT_UNLOCK_TIMEOUT: Duration = Duration::from_secs(300);
+
+#[derive(Debug, Serialize, Deserialize)]
+struct SealedSecret {
+    salt: String,
+    nonce: String,
+    ciphertext: String,
+}
+
+fn derive_key(passphrase: &[u8], salt: &[u8]) -> Result<[u8; 32], String> {
+    let mut key = [0u8; 32];
+    Argon2::default()
+        .hash_password_into(passphrase, salt, &mut key)
+        .map_err(|e| e.to_string())?;
+    Ok(key)
+}
+
+/// Encrypts `plaintext` with a key derived from `passphrase` under a fresh
+/// random salt, using a fresh random nonce, returning the salt/nonce/ciphertext bundle as a JSON string
+/// suitable for storing as the keyring value.
+pub fn seal(passphrase: &[u8], plaintext: &[u8]) -> Result<String, String> {
+    let mut salt = [0u8; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+    let mut nonce_bytes = [0u8; NONCE_LEN];
+    OsRng.fill_bytes(&mut nonce_bytes);
+
+    let key = derive_key(passphrase, &salt)?;
+    let cipher = XChaCha20Poly1305::new(key.as_ref().into());
+    let nonce = XNonce::from_slice(&nonce_bytes);
+    let ciphertext = cipher
+        .encrypt(nonce, plaintext)
+        .map_err(|_| "failed to seal secret".to_string())?;
+
+    let sealed = SealedSecret {
+        salt: STANDARD.encode(salt),
+        nonce: STANDARD.encode(nonce_bytes),
+        ciphertext: STANDARD.encode(ciphertext),
+    };
+    serde_json::to_string(&sealed).map_err(|e| e.to_string())
+}
+
+/// Decrypts a bundle produced by `seal`. Fails cleanly (no panic) on a wrong
+/// passphrase or corrupted data: a wrong passphrase or tampered ciphertext
+/// surfaces as an AEAD auth-tag mismatch, a malformed bundle as a decode error.
+pub fn unseal(passphrase: &[u8], sealed_json: &str) -> Result<Vec<u8>, String> {
+    let sealed: SealedSecret = serde_json::from_str(sealed_json).map_err(|e| e.to_string())?;
+    let salt = STANDARD.decode(&sealed.salt).map_err(|e| e.to_string())?;
+    let nonce_bytes = STANDARD.decode(&sealed.nonce).map_err(|e| e.to_string())?;
+    if nonce_bytes.len() != NONCE_LEN {
+        return Err("corrupted vault data: bad nonce length".to_string());
+    }
+    let ciphertext = STANDARD.decode(&sealed.ciphertext).map_err(|e| e.to_string())?;
+
+    let key = derive_key(passphrase, &salt)?;
+    let cipher = XChaCha20Poly1305::new(key.as_ref().into());
+    let nonce = XNonce::from_slice(&nonce_bytes);
+    cipher
+        .decrypt(nonce, ciphertext.as_ref())
+        .map_err(|_| "incorrect passphrase".to_string())
+}
+
+/// Caches an unlocked passphrase in memory for a configurable timeout so
+/// `get_alpaca_credentials_vaulted` doesn't need to re-prompt on every call.
+#[derive(Default)]
+pub struct VaultState {
+    cached: Mutex<Option<(Vec<u8>, Instant, Duration)>>,
+}
+
+impl VaultState {
+    pub fn unlock(&self, passphrase: Vec<u8>, timeout: Option<Duration>) {
+        let timeout = timeout.unwrap_or(DEFAULT_UNLOCK_TIMEOUT);
+        if let Ok(mut guard) = self.cached.lock() {
+            *guard = Some((passphrase, Instant::now(), timeout));
+        }
+    }
+
+    pub fn lock(&self) {
+        if let Ok(mut guard) = self.cached.lock() {
+            *guard = None;
+        }
+    }
+
+    /// Returns the cached passphrase if present and not past its timeout.
+    pub fn cached_passphrase(&self) -> Option<Vec<u8>> {
+        let mut guard = self.cached.lock().ok()?;
+        match guard.as_ref() {
+            Some((passphrase, unlocked_at, timeout)) if unlocked_at.elapsed() < *timeout => {
+                Some(passphrase.clone())
+            }
+            Some(_) => {
+                *guard = None;
+                None
+            }
+            None => None,
+        }
+    }
+}